@@ -5,10 +5,11 @@ use crate::vector::defn::Defn;
 use crate::vector::{Feature, FieldValue, Geometry};
 use crate::{dataset::Dataset, gdal_major_object::MajorObject};
 use gdal_sys::{
-    self, GDALMajorObjectH, OGREnvelope, OGRErr, OGRFieldDefnH, OGRFieldType, OGRLayerH,
+    self, GDALMajorObjectH, OGREnvelope, OGRErr, OGRFieldDefnH, OGRFieldType, OGRGeomFieldDefnH,
+    OGRLayerH,
 };
-use libc::c_int;
-use std::ptr::null_mut;
+use libc::{c_char, c_int};
+use std::ptr::{null, null_mut};
 use std::{convert::TryInto, ffi::CString, marker::PhantomData};
 
 use crate::errors::*;
@@ -81,6 +82,108 @@ impl LayerCaps {
     }
 }
 
+/// Geometry type of a layer, as returned by [`Layer::geometry_type`].
+///
+/// Mirrors the basic `wkbGeometryType` values from the OGR C API and their 25D, M and ZM
+/// variants. Other values (curve, surface, TIN, ... types) are reported as
+/// [`GeometryType::Unknown`].
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryType {
+    /// Geometry type is not known in advance (`wkbUnknown`), or is a `wkbGeometryType` value
+    /// without a variant of its own in this enum.
+    Unknown,
+    /// Non-spatial layer, i.e. an attribute table.
+    None,
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    /// Point with an additional Z coordinate (legacy `2.5D` encoding).
+    Point25D,
+    /// Line string with an additional Z coordinate (legacy `2.5D` encoding).
+    LineString25D,
+    /// Polygon with an additional Z coordinate (legacy `2.5D` encoding).
+    Polygon25D,
+    /// Multi-point with an additional Z coordinate (legacy `2.5D` encoding).
+    MultiPoint25D,
+    /// Multi-line-string with an additional Z coordinate (legacy `2.5D` encoding).
+    MultiLineString25D,
+    /// Multi-polygon with an additional Z coordinate (legacy `2.5D` encoding).
+    MultiPolygon25D,
+    /// Geometry collection with an additional Z coordinate (legacy `2.5D` encoding).
+    GeometryCollection25D,
+    /// Point with an additional measure (`M`) value.
+    PointM,
+    /// Line string with an additional measure (`M`) value.
+    LineStringM,
+    /// Polygon with an additional measure (`M`) value.
+    PolygonM,
+    /// Multi-point with an additional measure (`M`) value.
+    MultiPointM,
+    /// Multi-line-string with an additional measure (`M`) value.
+    MultiLineStringM,
+    /// Multi-polygon with an additional measure (`M`) value.
+    MultiPolygonM,
+    /// Geometry collection with an additional measure (`M`) value.
+    GeometryCollectionM,
+    /// Point with both a Z coordinate and a measure (`M`) value.
+    PointZM,
+    /// Line string with both a Z coordinate and a measure (`M`) value.
+    LineStringZM,
+    /// Polygon with both a Z coordinate and a measure (`M`) value.
+    PolygonZM,
+    /// Multi-point with both a Z coordinate and a measure (`M`) value.
+    MultiPointZM,
+    /// Multi-line-string with both a Z coordinate and a measure (`M`) value.
+    MultiLineStringZM,
+    /// Multi-polygon with both a Z coordinate and a measure (`M`) value.
+    MultiPolygonZM,
+    /// Geometry collection with both a Z coordinate and a measure (`M`) value.
+    GeometryCollectionZM,
+}
+
+impl GeometryType {
+    fn from_c(ty: gdal_sys::OGRwkbGeometryType::Type) -> Self {
+        use gdal_sys::OGRwkbGeometryType::*;
+        match ty {
+            wkbNone => Self::None,
+            wkbPoint => Self::Point,
+            wkbLineString => Self::LineString,
+            wkbPolygon => Self::Polygon,
+            wkbMultiPoint => Self::MultiPoint,
+            wkbMultiLineString => Self::MultiLineString,
+            wkbMultiPolygon => Self::MultiPolygon,
+            wkbGeometryCollection => Self::GeometryCollection,
+            wkbPoint25D => Self::Point25D,
+            wkbLineString25D => Self::LineString25D,
+            wkbPolygon25D => Self::Polygon25D,
+            wkbMultiPoint25D => Self::MultiPoint25D,
+            wkbMultiLineString25D => Self::MultiLineString25D,
+            wkbMultiPolygon25D => Self::MultiPolygon25D,
+            wkbGeometryCollection25D => Self::GeometryCollection25D,
+            wkbPointM => Self::PointM,
+            wkbLineStringM => Self::LineStringM,
+            wkbPolygonM => Self::PolygonM,
+            wkbMultiPointM => Self::MultiPointM,
+            wkbMultiLineStringM => Self::MultiLineStringM,
+            wkbMultiPolygonM => Self::MultiPolygonM,
+            wkbGeometryCollectionM => Self::GeometryCollectionM,
+            wkbPointZM => Self::PointZM,
+            wkbLineStringZM => Self::LineStringZM,
+            wkbPolygonZM => Self::PolygonZM,
+            wkbMultiPointZM => Self::MultiPointZM,
+            wkbMultiLineStringZM => Self::MultiLineStringZM,
+            wkbMultiPolygonZM => Self::MultiPolygonZM,
+            wkbGeometryCollectionZM => Self::GeometryCollectionZM,
+            _ => Self::Unknown,
+        }
+    }
+}
+
 /// Layer in a vector dataset
 ///
 /// ```
@@ -180,6 +283,51 @@ impl<'a> Layer<'a> {
         _string(rv)
     }
 
+    /// Returns the geometry type of this layer.
+    ///
+    /// Refer [OGR_L_GetGeomType](https://gdal.org/doxygen/classOGRLayer.html#a0bb2a4fd0788e70dee57bc101e5a5bc5)
+    pub fn geometry_type(&self) -> GeometryType {
+        let ty = unsafe { gdal_sys::OGR_L_GetGeomType(self.c_layer) };
+        GeometryType::from_c(ty)
+    }
+
+    /// Returns the number of geometry fields on this layer.
+    ///
+    /// Most layers have exactly one, but formats supporting [`LayerCaps::OLCCreateGeomField`]
+    /// (GPKG, GeoJSON sequences, PostGIS, ...) may expose several.
+    ///
+    /// Refer [OGR_L_GetGeomFieldCount](https://gdal.org/doxygen/classOGRLayer.html)
+    pub fn geom_field_count(&self) -> i32 {
+        unsafe { gdal_sys::OGR_L_GetGeomFieldCount(self.c_layer) }
+    }
+
+    /// Returns the name, geometry type and spatial reference of the geometry field at
+    /// `index`, or `None` if `index` is out of range.
+    ///
+    /// Refer [OGR_L_GetGeomFieldDefn](https://gdal.org/doxygen/classOGRLayer.html)
+    pub fn geom_field(&self, index: i32) -> Option<GeomField> {
+        let c_geom_field_defn = unsafe { gdal_sys::OGR_L_GetGeomFieldDefn(self.c_layer, index) };
+        if c_geom_field_defn.is_null() {
+            return None;
+        }
+        let name = unsafe { _string(gdal_sys::OGR_GFld_GetNameRef(c_geom_field_defn)) };
+        let geometry_type =
+            GeometryType::from_c(unsafe { gdal_sys::OGR_GFld_GetType(c_geom_field_defn) });
+        let spatial_ref = unsafe {
+            let c_srs = gdal_sys::OGR_GFld_GetSpatialRef(c_geom_field_defn);
+            if c_srs.is_null() {
+                None
+            } else {
+                SpatialRef::from_c_obj(c_srs).ok()
+            }
+        };
+        Some(GeomField {
+            name,
+            geometry_type,
+            spatial_ref,
+        })
+    }
+
     pub fn has_capability(&self, capability: LayerCaps) -> bool {
         unsafe {
             gdal_sys::OGR_L_TestCapability(self.c_layer, capability.into_cstring().as_ptr()) == 1
@@ -233,6 +381,173 @@ impl<'a> Layer<'a> {
         Ok(())
     }
 
+    /// Deletes the feature with the given feature id `fid` from this layer.
+    ///
+    /// Requires [`LayerCaps::OLCDeleteFeature`]. If no feature with `fid` exists, the
+    /// returned error wraps `OGRERR_NON_EXISTING_FEATURE`, which callers can distinguish by
+    /// matching on [`GdalError::OgrError`]'s `err` field.
+    ///
+    /// Refer [OGR_L_DeleteFeature](https://gdal.org/doxygen/classOGRLayer.html#afb4069b89f68d9aef6b5a5fc1f7e2e03)
+    pub fn delete_feature(&mut self, fid: u64) -> Result<()> {
+        if !self.has_capability(LayerCaps::OLCDeleteFeature) {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_UNSUPPORTED_OPERATION,
+                method_name: "OGR_L_DeleteFeature",
+            });
+        }
+        let rv = unsafe { gdal_sys::OGR_L_DeleteFeature(self.c_layer, fid as i64) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_DeleteFeature",
+            });
+        }
+        Ok(())
+    }
+
+    /// Rewrites an existing feature, identified by its feature id, with the fields and
+    /// geometry carried by `feature` (for instance one previously read via [`Layer::feature`]
+    /// or the [`FeatureIterator`] and then mutated).
+    ///
+    /// Requires [`LayerCaps::OLCRandomWrite`]. If no feature with a matching feature id
+    /// exists, the returned error wraps `OGRERR_NON_EXISTING_FEATURE`.
+    ///
+    /// Refer [OGR_L_SetFeature](https://gdal.org/doxygen/classOGRLayer.html#a68d9f6d507a09ce6057d28fdc1fcfa0c)
+    pub fn set_feature(&self, feature: Feature) -> Result<()> {
+        if !self.has_capability(LayerCaps::OLCRandomWrite) {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_UNSUPPORTED_OPERATION,
+                method_name: "OGR_L_SetFeature",
+            });
+        }
+        let rv = unsafe { gdal_sys::OGR_L_SetFeature(self.c_layer, feature.c_feature()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_SetFeature",
+            });
+        }
+        Ok(())
+    }
+
+    /// Reorders all fields according to `new_order`, a permutation of `0..field_count`
+    /// giving, for each new position, the index of the field that should be moved there.
+    ///
+    /// Requires [`LayerCaps::OLCReorderFields`]. `new_order` must have exactly
+    /// `self.defn().field_count()` elements and contain each index in `0..field_count`
+    /// exactly once; otherwise `OGR_L_ReorderFields` would read past the end of the array
+    /// it is given, so this is checked up front.
+    ///
+    /// Refer [OGR_L_ReorderFields](https://gdal.org/doxygen/classOGRLayer.html)
+    pub fn reorder_fields(&mut self, new_order: &[usize]) -> Result<()> {
+        let field_count = self.defn().field_count();
+        let mut sorted = new_order.to_vec();
+        sorted.sort_unstable();
+        if new_order.len() != field_count || sorted.iter().enumerate().any(|(i, &v)| i != v) {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_FAILURE,
+                method_name: "OGR_L_ReorderFields",
+            });
+        }
+        if !self.has_capability(LayerCaps::OLCReorderFields) {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_UNSUPPORTED_OPERATION,
+                method_name: "OGR_L_ReorderFields",
+            });
+        }
+        let mut new_order: Vec<c_int> = new_order.iter().map(|&i| i as c_int).collect();
+        let rv = unsafe { gdal_sys::OGR_L_ReorderFields(self.c_layer, new_order.as_mut_ptr()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_ReorderFields",
+            });
+        }
+        Ok(())
+    }
+
+    /// Moves the field at `old_pos` to `new_pos`, shifting the fields in between to make
+    /// room.
+    ///
+    /// Requires [`LayerCaps::OLCReorderFields`].
+    ///
+    /// Refer [OGR_L_ReorderField](https://gdal.org/doxygen/classOGRLayer.html)
+    pub fn reorder_field(&mut self, old_pos: usize, new_pos: usize) -> Result<()> {
+        if !self.has_capability(LayerCaps::OLCReorderFields) {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_UNSUPPORTED_OPERATION,
+                method_name: "OGR_L_ReorderField",
+            });
+        }
+        let rv = unsafe {
+            gdal_sys::OGR_L_ReorderField(self.c_layer, old_pos as c_int, new_pos as c_int)
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_ReorderField",
+            });
+        }
+        Ok(())
+    }
+
+    /// Deletes the field at `index` from this layer's schema.
+    ///
+    /// Requires [`LayerCaps::OLCDeleteField`].
+    ///
+    /// Refer [OGR_L_DeleteField](https://gdal.org/doxygen/classOGRLayer.html)
+    pub fn delete_field(&mut self, index: usize) -> Result<()> {
+        if !self.has_capability(LayerCaps::OLCDeleteField) {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_UNSUPPORTED_OPERATION,
+                method_name: "OGR_L_DeleteField",
+            });
+        }
+        let rv = unsafe { gdal_sys::OGR_L_DeleteField(self.c_layer, index as c_int) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_DeleteField",
+            });
+        }
+        Ok(())
+    }
+
+    /// Alters the field at `index` in place, changing only the attributes of the field
+    /// selected by `flags` to match `new_defn`.
+    ///
+    /// Requires [`LayerCaps::OLCAlterFieldDefn`].
+    ///
+    /// Refer [OGR_L_AlterFieldDefn](https://gdal.org/doxygen/classOGRLayer.html)
+    pub fn alter_field_defn(
+        &mut self,
+        index: usize,
+        new_defn: &FieldDefn,
+        flags: AlterFieldFlags,
+    ) -> Result<()> {
+        if !self.has_capability(LayerCaps::OLCAlterFieldDefn) {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_UNSUPPORTED_OPERATION,
+                method_name: "OGR_L_AlterFieldDefn",
+            });
+        }
+        let rv = unsafe {
+            gdal_sys::OGR_L_AlterFieldDefn(
+                self.c_layer,
+                index as c_int,
+                new_defn.c_obj,
+                flags.bits(),
+            )
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_AlterFieldDefn",
+            });
+        }
+        Ok(())
+    }
+
     /// Returns the number of features in this layer, even if it requires expensive calculation.
     ///
     /// Some drivers will actually scan the entire layer once to count objects.
@@ -367,6 +682,149 @@ impl<'a> Layer<'a> {
             gdal_sys::OGR_L_SetAttributeFilter(self.c_layer, null_mut());
         }
     }
+
+    /// Ignore the given fields (and, via the special `"OGR_GEOMETRY"` token, the geometry)
+    /// when reading features through [`Layer::feature`] or [`Layer::features`].
+    ///
+    /// Requires [`LayerCaps::OLCIgnoreFields`].
+    ///
+    /// Refer [OGR_L_SetIgnoredFields](https://gdal.org/doxygen/classOGRLayer.html#a85d8ff68cdbb5aaf8ab3c25b1fcf6087)
+    pub fn set_ignored_fields<T: AsRef<str>>(&mut self, field_names: &[T]) -> Result<()> {
+        if !self.has_capability(LayerCaps::OLCIgnoreFields) {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_UNSUPPORTED_OPERATION,
+                method_name: "OGR_L_SetIgnoredFields",
+            });
+        }
+        let c_strings = field_names
+            .iter()
+            .map(|name| CString::new(name.as_ref()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let mut c_ptrs: Vec<*const c_char> = c_strings.iter().map(|s| s.as_ptr()).collect();
+        c_ptrs.push(null());
+
+        let rv = unsafe { gdal_sys::OGR_L_SetIgnoredFields(self.c_layer, c_ptrs.as_ptr()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_SetIgnoredFields",
+            });
+        }
+        Ok(())
+    }
+
+    /// Convenience around [`Layer::set_ignored_fields`] that keeps only the given fields,
+    /// ignoring everything else. Include the special `"OGR_GEOMETRY"` token in `field_names`
+    /// to also keep the geometry column; omitting it ignores the geometry.
+    pub fn set_only_fields(&mut self, field_names: &[&str]) -> Result<()> {
+        let mut ignored: Vec<String> = self
+            .defn()
+            .fields()
+            .map(|field| field.name())
+            .filter(|name| !field_names.contains(&name.as_str()))
+            .collect();
+        if !field_names.contains(&"OGR_GEOMETRY") {
+            ignored.push("OGR_GEOMETRY".to_string());
+        }
+        self.set_ignored_fields(&ignored)
+    }
+
+    /// Begin a transaction on this layer.
+    ///
+    /// Depending on the driver, this allows a batch of edits (e.g. repeated
+    /// [`create_feature_fields`](Layer::create_feature_fields) calls) to be committed as a
+    /// single atomic unit instead of paying the cost of committing each one individually.
+    ///
+    /// Returns a [`Transaction`] guard that commits on drop unless
+    /// [`Transaction::rollback`] is called first.
+    ///
+    /// Refer [OGR_L_StartTransaction](https://gdal.org/doxygen/classOGRLayer.html#a8884396758d3bc4ffc79d7848c436f33)
+    pub fn start_transaction(&mut self) -> Result<Transaction<'_, 'a>> {
+        let rv = unsafe { gdal_sys::OGR_L_StartTransaction(self.c_layer) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_StartTransaction",
+            });
+        }
+        Ok(Transaction::new(self))
+    }
+}
+
+/// RAII guard for a transaction started with [`Layer::start_transaction`].
+///
+/// The transaction is committed on drop unless [`Transaction::rollback`] was called. Use
+/// [`Transaction::commit`] to commit explicitly and observe a possible error.
+pub struct Transaction<'a, 'ds> {
+    layer: &'a mut Layer<'ds>,
+    complete: bool,
+}
+
+impl<'a, 'ds> Transaction<'a, 'ds> {
+    fn new(layer: &'a mut Layer<'ds>) -> Self {
+        Transaction {
+            layer,
+            complete: false,
+        }
+    }
+
+    /// Returns a reference to the underlying layer.
+    pub fn layer(&self) -> &Layer<'ds> {
+        self.layer
+    }
+
+    /// Returns a mutable reference to the underlying layer.
+    pub fn layer_mut(&mut self) -> &mut Layer<'ds> {
+        self.layer
+    }
+
+    /// Commit this transaction.
+    ///
+    /// Refer [OGR_L_CommitTransaction](https://gdal.org/doxygen/classOGRLayer.html#a3b8b64a9545b56ea290a353ee7a96a42)
+    pub fn commit(mut self) -> Result<()> {
+        self.complete = true;
+        let rv = unsafe { gdal_sys::OGR_L_CommitTransaction(self.layer.c_layer) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_CommitTransaction",
+            });
+        }
+        Ok(())
+    }
+
+    /// Roll back this transaction, discarding any edits made since
+    /// [`Layer::start_transaction`] was called.
+    ///
+    /// Refer [OGR_L_RollbackTransaction](https://gdal.org/doxygen/classOGRLayer.html#a7660816c37f2d305d52f0fe3317e7765)
+    pub fn rollback(mut self) -> Result<()> {
+        self.complete = true;
+        let rv = unsafe { gdal_sys::OGR_L_RollbackTransaction(self.layer.c_layer) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_RollbackTransaction",
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'ds> Drop for Transaction<'a, 'ds> {
+    fn drop(&mut self) {
+        if !self.complete {
+            let rv = unsafe { gdal_sys::OGR_L_CommitTransaction(self.layer.c_layer) };
+            if rv != OGRErr::OGRERR_NONE {
+                // Call `Transaction::commit` explicitly to observe and handle this error
+                // instead of relying on the implicit commit-on-drop.
+                eprintln!(
+                    "gdal: implicit commit in Transaction::drop failed with {:?}; \
+                     the pending edits were not persisted",
+                    rv
+                );
+            }
+        }
+    }
 }
 
 pub struct FeatureIterator<'a> {
@@ -411,6 +869,36 @@ impl<'a> FeatureIterator<'a> {
     }
 }
 
+/// Flags selecting which attributes of a field definition [`Layer::alter_field_defn`] should
+/// change. Combine multiple flags with the bitwise-or operator, e.g. `NAME | TYPE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlterFieldFlags(c_int);
+
+impl AlterFieldFlags {
+    /// Alter the field name.
+    pub const NAME: AlterFieldFlags = AlterFieldFlags(gdal_sys::ALTER_NAME_FLAG);
+    /// Alter the field type.
+    pub const TYPE: AlterFieldFlags = AlterFieldFlags(gdal_sys::ALTER_TYPE_FLAG);
+    /// Alter the field width and precision.
+    pub const WIDTH_PRECISION: AlterFieldFlags =
+        AlterFieldFlags(gdal_sys::ALTER_WIDTH_PRECISION_FLAG);
+    /// Alter every attribute covered by GDAL's `ALTER_ALL_FLAG`, which besides the field
+    /// name, type and width/precision also includes its nullability and default value.
+    pub const ALL: AlterFieldFlags = AlterFieldFlags(gdal_sys::ALTER_ALL_FLAG);
+
+    fn bits(self) -> c_int {
+        self.0
+    }
+}
+
+impl std::ops::BitOr for AlterFieldFlags {
+    type Output = AlterFieldFlags;
+
+    fn bitor(self, rhs: AlterFieldFlags) -> AlterFieldFlags {
+        AlterFieldFlags(self.0 | rhs.0)
+    }
+}
+
 pub struct FieldDefn {
     c_obj: OGRFieldDefnH,
 }
@@ -453,3 +941,329 @@ impl FieldDefn {
         Ok(())
     }
 }
+
+/// Name, geometry type and spatial reference of a geometry field, as returned by
+/// [`Layer::geom_field`].
+#[derive(Debug)]
+pub struct GeomField {
+    name: String,
+    geometry_type: GeometryType,
+    spatial_ref: Option<SpatialRef>,
+}
+
+impl GeomField {
+    /// Name of this geometry field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Geometry type of this geometry field.
+    pub fn geometry_type(&self) -> GeometryType {
+        self.geometry_type
+    }
+
+    /// Spatial reference of this geometry field, if any.
+    pub fn spatial_ref(&self) -> Option<&SpatialRef> {
+        self.spatial_ref.as_ref()
+    }
+}
+
+/// Definition of a geometry field, used to add an additional geometry column to a layer via
+/// [`GeomFieldDefn::add_to_layer`].
+///
+/// Most layers have a single, default geometry column created implicitly when the layer
+/// itself is created; this type is for formats (GPKG, GeoJSON sequences, PostGIS, ...) that
+/// support more than one, gated behind [`LayerCaps::OLCCreateGeomField`].
+pub struct GeomFieldDefn {
+    c_obj: OGRGeomFieldDefnH,
+}
+
+impl Drop for GeomFieldDefn {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::OGR_GFld_Destroy(self.c_obj) };
+    }
+}
+
+impl MajorObject for GeomFieldDefn {
+    unsafe fn gdal_object_ptr(&self) -> GDALMajorObjectH {
+        self.c_obj
+    }
+}
+
+impl GeomFieldDefn {
+    pub fn new(name: &str, field_type: gdal_sys::OGRwkbGeometryType::Type) -> Result<GeomFieldDefn> {
+        let c_str = CString::new(name)?;
+        let c_obj = unsafe { gdal_sys::OGR_GFld_Create(c_str.as_ptr(), field_type) };
+        if c_obj.is_null() {
+            return Err(_last_null_pointer_err("OGR_GFld_Create"));
+        };
+        Ok(GeomFieldDefn { c_obj })
+    }
+
+    pub fn set_spatial_ref(&self, spatial_ref: &SpatialRef) {
+        unsafe { gdal_sys::OGR_GFld_SetSpatialRef(self.c_obj, spatial_ref.to_c_hsrs()) };
+    }
+
+    pub fn set_type(&self, field_type: gdal_sys::OGRwkbGeometryType::Type) {
+        unsafe { gdal_sys::OGR_GFld_SetType(self.c_obj, field_type) };
+    }
+
+    pub fn add_to_layer(&self, layer: &Layer) -> Result<()> {
+        let rv = unsafe { gdal_sys::OGR_L_CreateGeomField(layer.c_layer(), self.c_obj, 1) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_CreateGeomField",
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::driver::Driver;
+    use crate::vector::Geometry;
+    use std::path::Path;
+
+    /// A fresh, writable in-memory layer with a couple of attribute fields, for tests that
+    /// need to create/update/delete features rather than just read a fixture.
+    fn writable_dataset() -> Dataset {
+        let driver = Driver::get_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        {
+            let layer = ds.create_layer(Default::default()).unwrap();
+            layer
+                .create_defn_fields(&[
+                    ("name", OGRFieldType::OFTString),
+                    ("count", OGRFieldType::OFTInteger),
+                ])
+                .unwrap();
+        }
+        ds
+    }
+
+    /// A fresh, writable GPKG layer backed by `/vsimem/`. Unlike the `Memory` driver, GPKG
+    /// actually implements `OLCTransactions`, so this is needed to exercise
+    /// `start_transaction`/`commit`/`rollback` for real.
+    fn transactional_dataset(path: &str) -> Dataset {
+        let driver = Driver::get_by_name("GPKG").unwrap();
+        let mut ds = driver.create_vector_only(path).unwrap();
+        {
+            let layer = ds.create_layer(Default::default()).unwrap();
+            layer
+                .create_defn_fields(&[
+                    ("name", OGRFieldType::OFTString),
+                    ("count", OGRFieldType::OFTInteger),
+                ])
+                .unwrap();
+        }
+        ds
+    }
+
+    #[test]
+    fn transaction_commit_persists_features() {
+        let mut ds = transactional_dataset("/vsimem/transaction_commit_persists_features.gpkg");
+        let mut layer = ds.layer(0).unwrap();
+
+        {
+            let mut txn = layer.start_transaction().unwrap();
+            txn.layer_mut()
+                .create_feature_fields(
+                    Geometry::empty(gdal_sys::OGRwkbGeometryType::wkbPoint).unwrap(),
+                    &["name", "count"],
+                    &[FieldValue::StringValue("a".into()), FieldValue::IntegerValue(1)],
+                )
+                .unwrap();
+            txn.commit().unwrap();
+        }
+
+        assert_eq!(layer.feature_count(), 1);
+    }
+
+    #[test]
+    fn reorder_fields_rejects_wrong_length() {
+        let mut ds = writable_dataset();
+        let mut layer = ds.layer(0).unwrap();
+
+        let err = layer.reorder_fields(&[0]).unwrap_err();
+        assert!(matches!(
+            err,
+            GdalError::OgrError {
+                err: OGRErr::OGRERR_FAILURE,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn reorder_fields_rejects_non_permutation() {
+        let mut ds = writable_dataset();
+        let mut layer = ds.layer(0).unwrap();
+
+        let err = layer.reorder_fields(&[0, 0]).unwrap_err();
+        assert!(matches!(
+            err,
+            GdalError::OgrError {
+                err: OGRErr::OGRERR_FAILURE,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn reorder_fields_swaps_order() {
+        let mut ds = writable_dataset();
+        let mut layer = ds.layer(0).unwrap();
+
+        layer.reorder_fields(&[1, 0]).unwrap();
+
+        let names: Vec<String> = layer.defn().fields().map(|f| f.name()).collect();
+        assert_eq!(names, vec!["count".to_string(), "name".to_string()]);
+    }
+
+    #[test]
+    fn alter_field_defn_renames_field() {
+        let mut ds = writable_dataset();
+        let mut layer = ds.layer(0).unwrap();
+
+        let new_defn = FieldDefn::new("renamed", OGRFieldType::OFTString).unwrap();
+        layer
+            .alter_field_defn(0, &new_defn, AlterFieldFlags::NAME)
+            .unwrap();
+
+        assert_eq!(layer.defn().fields().next().unwrap().name(), "renamed");
+    }
+
+    #[test]
+    fn geom_field_create_and_query_round_trip() {
+        let mut ds = writable_dataset();
+        let mut layer = ds.layer(0).unwrap();
+
+        let geom_field_defn =
+            GeomFieldDefn::new("extra_geom", gdal_sys::OGRwkbGeometryType::wkbPolygon).unwrap();
+        geom_field_defn.add_to_layer(&layer).unwrap();
+
+        // Index 0 is the default geometry column created alongside the layer.
+        let extra = layer.geom_field(1).unwrap();
+        assert_eq!(extra.name(), "extra_geom");
+        assert_eq!(extra.geometry_type(), GeometryType::Polygon);
+
+        assert!(layer.geom_field(2).is_none());
+    }
+
+    #[test]
+    fn set_feature_edits_in_place() {
+        let mut ds = writable_dataset();
+        let mut layer = ds.layer(0).unwrap();
+        layer
+            .create_feature_fields(
+                Geometry::empty(gdal_sys::OGRwkbGeometryType::wkbPoint).unwrap(),
+                &["name", "count"],
+                &[
+                    FieldValue::StringValue("a".into()),
+                    FieldValue::IntegerValue(1),
+                ],
+            )
+            .unwrap();
+
+        let fid = layer.features().next().unwrap().fid().unwrap();
+        let mut feature = layer.feature(fid).unwrap();
+        feature
+            .set_field("count", &FieldValue::IntegerValue(2))
+            .unwrap();
+        layer.set_feature(feature).unwrap();
+
+        assert_eq!(
+            layer.feature(fid).unwrap().field("count").unwrap().unwrap(),
+            FieldValue::IntegerValue(2)
+        );
+    }
+
+    #[test]
+    fn delete_feature_removes_it() {
+        let mut ds = writable_dataset();
+        let mut layer = ds.layer(0).unwrap();
+        layer
+            .create_feature_fields(
+                Geometry::empty(gdal_sys::OGRwkbGeometryType::wkbPoint).unwrap(),
+                &["name", "count"],
+                &[
+                    FieldValue::StringValue("a".into()),
+                    FieldValue::IntegerValue(1),
+                ],
+            )
+            .unwrap();
+
+        let fid = layer.features().next().unwrap().fid().unwrap();
+        layer.delete_feature(fid).unwrap();
+
+        assert!(layer.feature(fid).is_none());
+    }
+
+    #[test]
+    fn set_only_fields_ignores_the_rest() {
+        // `Memory` doesn't advertise `OLCIgnoreFields`; GeoJSON does.
+        let driver = Driver::get_by_name("GeoJSON").unwrap();
+        let mut ds = driver
+            .create_vector_only("/vsimem/set_only_fields_ignores_the_rest.geojson")
+            .unwrap();
+        {
+            let layer = ds.create_layer(Default::default()).unwrap();
+            layer
+                .create_defn_fields(&[
+                    ("name", OGRFieldType::OFTString),
+                    ("count", OGRFieldType::OFTInteger),
+                ])
+                .unwrap();
+        }
+        let mut layer = ds.layer(0).unwrap();
+        layer
+            .create_feature_fields(
+                Geometry::empty(gdal_sys::OGRwkbGeometryType::wkbPoint).unwrap(),
+                &["name", "count"],
+                &[
+                    FieldValue::StringValue("a".into()),
+                    FieldValue::IntegerValue(1),
+                ],
+            )
+            .unwrap();
+
+        layer.set_only_fields(&["name"]).unwrap();
+
+        let feature = layer.features().next().unwrap();
+        assert_eq!(
+            feature.field("name").unwrap().unwrap(),
+            FieldValue::StringValue("a".into())
+        );
+        assert!(feature.field("count").unwrap().is_none());
+    }
+
+    #[test]
+    fn geometry_type_reports_fixture_type() {
+        let ds = Dataset::open(Path::new("fixtures/roads.geojson")).unwrap();
+        let layer = ds.layer(0).unwrap();
+        assert_eq!(layer.geometry_type(), GeometryType::LineString);
+    }
+
+    #[test]
+    fn transaction_rollback_discards_features() {
+        let mut ds = transactional_dataset("/vsimem/transaction_rollback_discards_features.gpkg");
+        let mut layer = ds.layer(0).unwrap();
+
+        {
+            let mut txn = layer.start_transaction().unwrap();
+            txn.layer_mut()
+                .create_feature_fields(
+                    Geometry::empty(gdal_sys::OGRwkbGeometryType::wkbPoint).unwrap(),
+                    &["name", "count"],
+                    &[FieldValue::StringValue("a".into()), FieldValue::IntegerValue(1)],
+                )
+                .unwrap();
+            txn.rollback().unwrap();
+        }
+
+        assert_eq!(layer.feature_count(), 0);
+    }
+}